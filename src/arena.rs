@@ -0,0 +1,88 @@
+use std::{cell::UnsafeCell, cmp::max};
+
+/// The default size of an arena chunk, in bytes.
+const DEFAULT_CHUNK: usize = 4096;
+
+struct ArenaInner {
+    /// Filled chunks, kept alive so the strings pushed into them stay valid.
+    full: Vec<String>,
+    /// The chunk currently being filled. Never reallocated while it holds
+    /// live strings, so pointers into it remain stable.
+    current: String,
+    /// A `(pointer, len)` pair per interned string, in insertion order.
+    spans: Vec<(*const u8, usize)>,
+}
+
+/// A chunked arena that stores interned strings.
+///
+/// Strings are appended into fixed-capacity chunks that are never moved or
+/// freed until the arena is dropped, so a `&str` handed out by [`get`] stays
+/// valid for the lifetime of the arena rather than the borrow.
+///
+/// [`get`]: InternerArena::get
+pub struct InternerArena {
+    inner: UnsafeCell<ArenaInner>,
+}
+
+impl Default for InternerArena {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: UnsafeCell::new(ArenaInner {
+                full: Vec::new(),
+                current: String::new(),
+                spans: Vec::new(),
+            }),
+        }
+    }
+}
+
+impl InternerArena {
+    /// Push a string into the arena, returning its index.
+    pub(crate) fn push_str(&self, s: &str) -> usize {
+        // SAFETY: no reference handed out by `get` aliases the mutable borrow
+        // below, as `get` only reads `spans` and the chunk bytes, never the
+        // parts mutated here.
+        let inner = unsafe { &mut *self.inner.get() };
+
+        if inner.current.len() + s.len() > inner.current.capacity() {
+            let cap = max(DEFAULT_CHUNK, s.len());
+            let old = std::mem::replace(&mut inner.current, String::with_capacity(cap));
+            if !old.is_empty() {
+                inner.full.push(old);
+            }
+        }
+
+        let start = inner.current.len();
+        inner.current.push_str(s);
+
+        // SAFETY: `current` had spare capacity for `s`, so `push_str` did not
+        // reallocate and this pointer stays valid until the chunk is dropped.
+        let ptr = unsafe { inner.current.as_ptr().add(start) };
+
+        let index = inner.spans.len();
+        inner.spans.push((ptr, s.len()));
+        index
+    }
+
+    /// Get the string stored at `index`, if there is one.
+    pub(crate) fn get(&self, index: usize) -> Option<&str> {
+        // SAFETY: see `push_str`; this only reads the arena's contents.
+        let inner = unsafe { &*self.inner.get() };
+        let &(ptr, len) = inner.spans.get(index)?;
+
+        // SAFETY: `ptr`/`len` describe bytes written by `push_str` from a
+        // `&str`, so they are valid UTF-8 and live as long as `self`.
+        unsafe {
+            let bytes = std::slice::from_raw_parts(ptr, len);
+            Some(std::str::from_utf8_unchecked(bytes))
+        }
+    }
+
+    /// The number of strings stored in the arena.
+    pub(crate) fn len(&self) -> usize {
+        // SAFETY: see `push_str`; this only reads `spans`.
+        let inner = unsafe { &*self.inner.get() };
+        inner.spans.len()
+    }
+}
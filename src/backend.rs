@@ -0,0 +1,145 @@
+use std::{cell::UnsafeCell, cmp::max};
+
+use crate::arena::InternerArena;
+
+/// A storage backend for the bytes of interned strings.
+///
+/// An [`Interner`](crate::Interner) is generic over its backend, letting the
+/// caller choose how the bytes are stored without changing the
+/// `intern`/`get_str` API. Two backends ship with the crate:
+///
+/// - [`InternerArena`], the default, stores strings in fixed-capacity chunks
+///   that are never moved, so references stay valid for the interner's
+///   lifetime. Each string is recorded as a `(pointer, len)` span.
+/// - [`StringBackend`] also stores strings in fixed-capacity chunks that are
+///   never moved, and records each string as a `(chunk, start, len)` span.
+///   Text is contiguous within a chunk, not across the whole backend.
+pub trait Backend {
+    /// Store `s`, returning the index it can be read back at with [`get`].
+    ///
+    /// [`get`]: Backend::get
+    fn push_str(&self, s: &str) -> usize;
+
+    /// Read back the string stored at `index`, if there is one.
+    fn get(&self, index: usize) -> Option<&str>;
+
+    /// The number of strings stored in the backend.
+    fn len(&self) -> usize;
+
+    /// Whether the backend stores no strings.
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Backend for InternerArena {
+    #[inline]
+    fn push_str(&self, s: &str) -> usize {
+        InternerArena::push_str(self, s)
+    }
+
+    #[inline]
+    fn get(&self, index: usize) -> Option<&str> {
+        InternerArena::get(self, index)
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        InternerArena::len(self)
+    }
+}
+
+/// The size, in bytes, of a [`StringBackend`] chunk.
+const CHUNK: usize = 4096;
+
+struct StringInner {
+    /// Filled chunks, kept alive so the strings packed into them stay valid.
+    full: Vec<String>,
+    /// The chunk currently being filled. Never reallocated while it holds live
+    /// strings, so the text inside it never moves.
+    current: String,
+    /// The `(chunk, start, len)` location of each string, in insertion order.
+    /// A chunk index of `full.len()` refers to `current`.
+    spans: Vec<(usize, usize, usize)>,
+}
+
+/// A [`Backend`] that packs all interned text into fixed-capacity `String`
+/// chunks alongside a compact `Vec<(chunk, start, len)>` span table.
+///
+/// Strings are stored contiguously within a chunk, giving minimal per-string
+/// overhead and good cache locality, which makes it a good fit for workloads
+/// that intern once and rarely — if ever — deallocate. Like [`InternerArena`]
+/// the chunks are never moved or freed until the backend is dropped, so a
+/// `&str` obtained from [`get`](Backend::get) stays valid for the interner's
+/// lifetime.
+pub struct StringBackend {
+    inner: UnsafeCell<StringInner>,
+}
+
+impl Default for StringBackend {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: UnsafeCell::new(StringInner {
+                full: Vec::new(),
+                current: String::new(),
+                spans: Vec::new(),
+            }),
+        }
+    }
+}
+
+impl Backend for StringBackend {
+    fn push_str(&self, s: &str) -> usize {
+        // SAFETY: no reference handed out by `get` aliases the mutable borrow
+        // below, as `get` only reads `spans` and the chunk text.
+        let inner = unsafe { &mut *self.inner.get() };
+
+        if inner.current.len() + s.len() > inner.current.capacity() {
+            let cap = max(CHUNK, s.len());
+            let old = std::mem::replace(&mut inner.current, String::with_capacity(cap));
+            if !old.is_empty() {
+                inner.full.push(old);
+            }
+        }
+
+        let chunk = inner.full.len();
+        let start = inner.current.len();
+        // `current` had spare capacity, so this does not reallocate and the
+        // already-stored text does not move.
+        inner.current.push_str(s);
+
+        let index = inner.spans.len();
+        inner.spans.push((chunk, start, s.len()));
+        index
+    }
+
+    fn get(&self, index: usize) -> Option<&str> {
+        // SAFETY: this only reads the chunks and span table.
+        let inner = unsafe { &*self.inner.get() };
+        let &(chunk, start, len) = inner.spans.get(index)?;
+        let buf = inner.full.get(chunk).unwrap_or(&inner.current);
+
+        // Reconstruct the `&str` from a raw pointer into the chunk's heap
+        // buffer rather than borrowing through the `UnsafeCell`, so its
+        // provenance is the (never-moved, never-freed) buffer and not the
+        // get-time shared borrow of `inner` — which a later `push_str` taking
+        // `&mut *self.inner.get()` would otherwise invalidate. This mirrors
+        // `InternerArena::get`.
+        //
+        // SAFETY: `start`/`len` were recorded by `push_str` for text written
+        // into `buf`, which is valid UTF-8 and lives as long as `self`.
+        unsafe {
+            let ptr = buf.as_ptr().add(start);
+            let bytes = std::slice::from_raw_parts(ptr, len);
+            Some(std::str::from_utf8_unchecked(bytes))
+        }
+    }
+
+    fn len(&self) -> usize {
+        // SAFETY: this only reads the span table.
+        let inner = unsafe { &*self.inner.get() };
+        inner.spans.len()
+    }
+}
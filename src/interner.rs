@@ -5,6 +5,7 @@ use rustc_hash::FxBuildHasher;
 
 use crate::{
     arena::InternerArena,
+    backend::Backend,
     istr::{Istr, IstrRepr},
 };
 
@@ -20,19 +21,24 @@ struct Lookup<I: IstrRepr> {
 }
 
 /// Storage for interned strings.
-pub struct Interner<I: IstrRepr = NonZeroUsize> {
+///
+/// The `B` type parameter selects the [`Backend`] that stores the string bytes.
+/// It defaults to [`InternerArena`], which keeps already-returned `&str`s valid
+/// for the interner's lifetime; [`StringBackend`](crate::StringBackend) is an
+/// alternative that records chunk indices and offsets instead of pointers.
+pub struct Interner<I: IstrRepr = NonZeroUsize, B: Backend = InternerArena> {
     lookup: RefCell<Lookup<I>>,
-    arena: InternerArena,
+    backend: B,
 }
 
-impl<I: IstrRepr> Default for Interner<I> {
+impl<I: IstrRepr, B: Backend + Default> Default for Interner<I, B> {
     fn default() -> Self {
         Self {
             lookup: RefCell::new(Lookup {
-                random_state: FxBuildHasher::default(),
+                random_state: FxBuildHasher,
                 table: HashTable::default(),
             }),
-            arena: InternerArena::default(),
+            backend: B::default(),
         }
     }
 }
@@ -45,14 +51,79 @@ impl Interner {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Create a new interner pre-filled with the given strings, in order.
+    ///
+    /// The strings are interned starting from index `0`, so their [`Istr`] IDs
+    /// are deterministic and can be named at compile time with the
+    /// [`static_symbols!`](crate::static_symbols) macro. Pair this with
+    /// `kw::ALL` (or your own ordered list) to match keywords by cheap ID
+    /// comparison rather than a hash lookup.
+    ///
+    /// ```rust
+    /// # use unpaid_intern::{static_symbols, Interner};
+    /// static_symbols! {
+    ///     pub mod kw {
+    ///         IF => "if",
+    ///         ELSE => "else",
+    ///     }
+    /// }
+    ///
+    /// let interner = Interner::with_preloaded(kw::ALL);
+    /// assert_eq!(interner.intern("if"), kw::IF);
+    /// assert_eq!(interner.get_str(kw::ELSE), Some("else"));
+    /// ```
+    #[inline]
+    pub fn with_preloaded(strings: &[&str]) -> Self {
+        let interner = Self::new();
+        interner.preload(strings);
+        interner
+    }
+}
+
+impl<I: IstrRepr, B: Backend> Interner<I, B> {
+    /// Create a new interner backed by the given storage [`Backend`].
+    #[inline]
+    pub fn with_backend(backend: B) -> Self {
+        Self {
+            lookup: RefCell::new(Lookup {
+                random_state: FxBuildHasher,
+                table: HashTable::default(),
+            }),
+            backend,
+        }
+    }
 }
 
-impl<I: IstrRepr> Interner<I> {
+impl<I: IstrRepr, B: Backend + Default> Interner<I, B> {
     /// Create a new interner with the inferred [`Istr`] backing type.
     #[inline]
     pub fn with_istr_repr() -> Self {
         Self::default()
     }
+}
+
+impl<I: IstrRepr, B: Backend> Interner<I, B> {
+    /// Pre-fill an *empty* interner with the given strings, in order.
+    ///
+    /// This must be called on a freshly created interner so that the first
+    /// string gets index `0`, the second index `1`, and so on — exactly the
+    /// indices the [`static_symbols!`](crate::static_symbols) macro bakes into
+    /// its constants.
+    ///
+    /// # Panics
+    /// Panics if the interner is not empty, or if interning a string would
+    /// exceed the available IDs.
+    pub fn preload(&self, strings: &[&str]) {
+        assert!(
+            self.backend.len() == 0,
+            "preloading must happen on an empty interner"
+        );
+        for (index, &string) in strings.iter().enumerate() {
+            let interned = self.intern(string);
+            debug_assert_eq!(interned.repr.to_index(), index);
+        }
+    }
 
     /// Intern a string, returning an interned string that it is cheap to copy and
     /// perform equality checks on. Strings are only stored in the interner once, no
@@ -90,14 +161,14 @@ impl<I: IstrRepr> Interner<I> {
 
         let entry = lookup.table.entry(
             hash,
-            |metadata| self.arena.get(metadata.interned.repr.to_index()) == Some(key),
+            |metadata| self.backend.get(metadata.interned.repr.to_index()) == Some(key),
             |metadata| metadata.hash,
         );
 
         let interned = match entry {
             Entry::Occupied(entry) => entry.get().interned,
             Entry::Vacant(entry) => {
-                let index = self.arena.push_str(key);
+                let index = self.backend.push_str(key);
                 let interned = Istr {
                     repr: I::from_index(index)?,
                 };
@@ -111,6 +182,52 @@ impl<I: IstrRepr> Interner<I> {
         Some(interned)
     }
 
+    /// Intern a fresh, non-deduplicated copy of a string.
+    ///
+    /// Unlike [`intern`](Interner::intern), this always allocates a new entry
+    /// and never consults or updates the deduplication table, so every call
+    /// returns a distinct [`Istr`] even when the text is identical. The name is
+    /// still recoverable with [`get_str`](Interner::get_str) — useful for
+    /// generated identifiers that need a readable name for diagnostics while
+    /// comparing unequal to every other symbol, as required for hygiene.
+    ///
+    /// Because the copy is never inserted into the table,
+    /// [`get_interned`](Interner::get_interned) keeps resolving to the normally
+    /// interned string, never to a gensym.
+    ///
+    /// ```rust
+    /// # use unpaid_intern::Interner;
+    /// #
+    /// # fn main() {
+    /// let interner = Interner::new();
+    ///
+    /// let a = interner.gensym("x");
+    /// let b = interner.gensym("x");
+    /// let x = interner.intern("x");
+    ///
+    /// assert_ne!(a, b);
+    /// assert_ne!(a, x);
+    /// assert_eq!(interner.get_str(a), Some("x"));
+    /// assert_eq!(interner.get_interned("x"), Some(x));
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if there are no more available IDs.
+    #[inline]
+    pub fn gensym(&self, key: &str) -> Istr<I> {
+        self.try_gensym(key).expect("too many interned strings")
+    }
+
+    /// Like [`Interner::gensym`], but non-panicking in the case that there are
+    /// no more available IDs.
+    pub fn try_gensym(&self, key: &str) -> Option<Istr<I>> {
+        let index = self.backend.push_str(key);
+        Some(Istr {
+            repr: I::from_index(index)?,
+        })
+    }
+
     /// Get an interned string if this string is interned, otherwise return `None`.
     ///
     /// ```rust
@@ -133,7 +250,7 @@ impl<I: IstrRepr> Interner<I> {
         lookup
             .table
             .find(hash, |metadata| {
-                self.arena.get(metadata.interned.repr.to_index()) == Some(key)
+                self.backend.get(metadata.interned.repr.to_index()) == Some(key)
             })
             .map(|metadata| metadata.interned)
     }
@@ -159,11 +276,36 @@ impl<I: IstrRepr> Interner<I> {
     /// ```
     #[inline]
     pub fn get_str(&self, interned: Istr<I>) -> Option<&str> {
-        self.arena.get(interned.repr.to_index())
+        self.backend.get(interned.repr.to_index())
+    }
+
+    /// The number of strings stored in the backend.
+    #[cfg(feature = "serialize")]
+    #[inline]
+    pub(crate) fn backend_len(&self) -> usize {
+        self.backend.len()
+    }
+
+    /// The string stored at `index`, in insertion order.
+    #[cfg(feature = "serialize")]
+    #[inline]
+    pub(crate) fn string_at(&self, index: usize) -> Option<&str> {
+        self.backend.get(index)
+    }
+
+    /// Whether the interner holds any gensyms (or otherwise has more stored
+    /// strings than deduplicated entries).
+    ///
+    /// Gensyms create backend slots that are absent from the dedup table, so a
+    /// mismatch between the two counts means at least one is present.
+    #[cfg(feature = "serialize")]
+    #[inline]
+    pub(crate) fn has_gensyms(&self) -> bool {
+        self.backend.len() != self.lookup.borrow().table.len()
     }
 }
 
-impl<I: IstrRepr> Index<Istr<I>> for Interner<I> {
+impl<I: IstrRepr, B: Backend> Index<Istr<I>> for Interner<I, B> {
     type Output = str;
 
     #[inline]
@@ -172,6 +314,46 @@ impl<I: IstrRepr> Index<Istr<I>> for Interner<I> {
     }
 }
 
+/// Declare a module of pre-interned symbols with associated [`Istr`] constants.
+///
+/// Each entry maps a constant name to its string. The constants are assigned
+/// the indices the strings would receive when interned in order onto an empty
+/// interner, so they are only valid if that same ordered list is preloaded
+/// (see [`Interner::with_preloaded`] and the generated `ALL` slice).
+///
+/// ```rust
+/// # use unpaid_intern::{static_symbols, Interner};
+/// static_symbols! {
+///     pub mod kw {
+///         IF => "if",
+///         ELSE => "else",
+///         WHILE => "while",
+///     }
+/// }
+///
+/// let interner = Interner::with_preloaded(kw::ALL);
+/// assert_eq!(interner.intern("while"), kw::WHILE);
+/// ```
+#[macro_export]
+macro_rules! static_symbols {
+    ($vis:vis mod $name:ident { $($konst:ident => $text:literal),* $(,)? }) => {
+        $vis mod $name {
+            $crate::static_symbols!(@consts 0usize; $($konst => $text,)*);
+
+            /// Every preloaded string, in the order its constant is assigned.
+            ///
+            /// Pass this to [`Interner::with_preloaded`](crate::Interner::with_preloaded).
+            pub const ALL: &[&str] = &[$($text),*];
+        }
+    };
+
+    (@consts $index:expr;) => {};
+    (@consts $index:expr; $konst:ident => $text:literal, $($rest_k:ident => $rest_t:literal,)*) => {
+        pub const $konst: $crate::Istr = $crate::Istr::from_raw_index($index);
+        $crate::static_symbols!(@consts $index + 1usize; $($rest_k => $rest_t,)*);
+    };
+}
+
 #[test]
 fn test_interner() {
     let interner = Interner::new();
@@ -16,6 +16,27 @@ pub struct Istr<Repr: IstrRepr = NonZeroUsize> {
     pub(crate) repr: Repr,
 }
 
+impl Istr {
+    /// Construct the [`Istr`] for a known zero-based `index`, in a `const`
+    /// context.
+    ///
+    /// This is how [`static_symbols!`](crate::static_symbols) generates its
+    /// associated constants: the first string interned gets index `0`, the
+    /// second index `1`, and so on, so a symbol's ID is known at compile time
+    /// as long as it is preloaded in the declared order onto an empty
+    /// interner.
+    ///
+    /// # Panics
+    /// Panics if `index` is `usize::MAX`, which cannot be represented.
+    #[inline]
+    pub const fn from_raw_index(index: usize) -> Self {
+        match NonZeroUsize::new(index.wrapping_add(1)) {
+            Some(repr) => Istr { repr },
+            None => panic!("interned string index out of range"),
+        }
+    }
+}
+
 /// A backing type for an [`Istr`].
 pub trait IstrRepr: Copy + sealed::Sealed {
     /// Convert a `usize` index to this backing type.
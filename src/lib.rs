@@ -0,0 +1,21 @@
+//! A fast string interner returning cheap, copyable [`Istr`] IDs.
+
+mod arena;
+mod backend;
+mod interner;
+mod istr;
+#[cfg(feature = "serialize")]
+mod serialize;
+#[cfg(feature = "sync")]
+mod sync;
+mod value;
+
+pub use arena::InternerArena;
+pub use backend::{Backend, StringBackend};
+pub use interner::Interner;
+pub use istr::{Istr, IstrRepr};
+pub use value::{ByteInterner, Id, ValueInterner};
+#[cfg(feature = "serialize")]
+pub use serialize::{InternSeed, SerializeAsStr};
+#[cfg(feature = "sync")]
+pub use sync::SyncInterner;
@@ -0,0 +1,188 @@
+//! Portable serialization of a whole [`Interner`] and of string-valued [`Istr`]s.
+//!
+//! A bare [`Istr`] serializes as its raw integer ID, which is only meaningful
+//! if the exact same interner is rebuilt identically on the other side. The
+//! impls here make serialized ASTs portable:
+//!
+//! - [`Interner`] serializes every stored string in index order and, on load,
+//!   rebuilds both the arena and the dedup table from scratch. Interners
+//!   containing gensyms cannot be serialized, as re-interning would collapse
+//!   their intentionally duplicated slots and shift every later index.
+//! - [`SerializeAsStr`] encodes a single [`Istr`] as its resolved text, and
+//!   [`InternSeed`] re-interns that text into a deserialization-time interner.
+
+use std::{marker::PhantomData, num::NonZeroUsize};
+
+use serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{self, DeserializeSeed, SeqAccess, Visitor},
+    ser::SerializeSeq,
+};
+
+use crate::{Backend, InternerArena, Interner, Istr, IstrRepr};
+
+impl<I: IstrRepr, B: Backend> Serialize for Interner<I, B> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        // Deserialization rebuilds the interner by re-interning each string in
+        // order, which deduplicates. That is lossless for a normally interned
+        // set (which never contains duplicates), but gensyms (chunk0-2)
+        // deliberately create duplicate slots whose distinct identity — and the
+        // indices of every later slot — would not survive the round trip. Rather
+        // than silently corrupt the IDs, refuse to serialize such an interner.
+        if self.has_gensyms() {
+            return Err(serde::ser::Error::custom(
+                "cannot serialize an interner containing gensyms",
+            ));
+        }
+
+        let len = self.backend_len();
+        let mut seq = serializer.serialize_seq(Some(len))?;
+        for index in 0..len {
+            let string = self
+                .string_at(index)
+                .expect("index in range is always present");
+            seq.serialize_element(string)?;
+        }
+        seq.end()
+    }
+}
+
+impl<'de, I: IstrRepr, B: Backend + Default> Deserialize<'de> for Interner<I, B> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct InternerVisitor<I, B>(PhantomData<fn() -> (I, B)>);
+
+        impl<'de, I: IstrRepr, B: Backend + Default> Visitor<'de> for InternerVisitor<I, B> {
+            type Value = Interner<I, B>;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("a sequence of interned strings in index order")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let interner = Interner::<I, B>::default();
+                // Re-interning each string in order rebuilds the arena and
+                // re-hashes every entry into a fresh dedup table, so
+                // `get_interned` keeps deduplicating correctly afterwards.
+                while let Some(string) = seq.next_element::<String>()? {
+                    interner
+                        .try_intern(&string)
+                        .ok_or_else(|| de::Error::custom("too many interned strings"))?;
+                }
+                Ok(interner)
+            }
+        }
+
+        deserializer.deserialize_seq(InternerVisitor(PhantomData))
+    }
+}
+
+impl<I: IstrRepr, B: Backend> Interner<I, B> {
+    /// Wrap an [`Istr`] so it serializes as its resolved text rather than its
+    /// raw ID, making it portable across interners.
+    ///
+    /// The returned value borrows this interner so the text can be looked up.
+    /// Deserialize the result back with [`intern_seed`](Interner::intern_seed).
+    #[inline]
+    pub fn serialize_as_str(&self, istr: Istr<I>) -> SerializeAsStr<'_, I, B> {
+        SerializeAsStr {
+            interner: self,
+            istr,
+        }
+    }
+
+    /// Build a [`DeserializeSeed`] that re-interns a string-encoded [`Istr`]
+    /// (see [`serialize_as_str`](Interner::serialize_as_str)) into this
+    /// interner.
+    #[inline]
+    pub fn intern_seed(&self) -> InternSeed<'_, I, B> {
+        InternSeed { interner: self }
+    }
+}
+
+/// An [`Istr`] paired with its interner so it serializes as its resolved text.
+///
+/// Created by [`Interner::serialize_as_str`].
+pub struct SerializeAsStr<'a, I: IstrRepr = NonZeroUsize, B: Backend = InternerArena> {
+    interner: &'a Interner<I, B>,
+    istr: Istr<I>,
+}
+
+impl<I: IstrRepr, B: Backend> Serialize for SerializeAsStr<'_, I, B> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let string = self
+            .interner
+            .get_str(self.istr)
+            .expect("interned string not in interner");
+        serializer.serialize_str(string)
+    }
+}
+
+/// A [`DeserializeSeed`] that reads a string and interns it, yielding the
+/// resulting [`Istr`].
+///
+/// Created by [`Interner::intern_seed`]. This is how an [`Istr`] encoded with
+/// [`SerializeAsStr`] is re-interned into a deserialization-time interner.
+pub struct InternSeed<'a, I: IstrRepr = NonZeroUsize, B: Backend = InternerArena> {
+    interner: &'a Interner<I, B>,
+}
+
+impl<'de, I: IstrRepr, B: Backend> DeserializeSeed<'de> for InternSeed<'_, I, B> {
+    type Value = Istr<I>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        self.interner
+            .try_intern(&string)
+            .ok_or_else(|| de::Error::custom("too many interned strings"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::de::DeserializeSeed;
+
+    use crate::Interner;
+
+    #[test]
+    fn roundtrip_preserves_strings_and_dedup() {
+        let interner = Interner::new();
+        let hello = interner.intern("hello");
+        let world = interner.intern("world");
+
+        let json = serde_json::to_string(&interner).unwrap();
+        let restored: Interner = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored.get_str(hello), Some("hello"));
+        assert_eq!(restored.get_str(world), Some("world"));
+
+        // The dedup table was rebuilt, so lookups still resolve to the same IDs
+        // and re-interning does not allocate a fresh slot.
+        assert_eq!(restored.get_interned("hello"), Some(hello));
+        assert_eq!(restored.intern("world"), world);
+    }
+
+    #[test]
+    fn refuses_interners_with_gensyms() {
+        let interner = Interner::new();
+        interner.intern("x");
+        interner.gensym("x");
+
+        assert!(serde_json::to_string(&interner).is_err());
+    }
+
+    #[test]
+    fn istr_roundtrips_as_str_across_interners() {
+        let source = Interner::new();
+        let hello = source.intern("hello");
+
+        let json = serde_json::to_string(&source.serialize_as_str(hello)).unwrap();
+        assert_eq!(json, "\"hello\"");
+
+        let target = Interner::new();
+        let mut de = serde_json::Deserializer::from_str(&json);
+        let reinterned = target.intern_seed().deserialize(&mut de).unwrap();
+
+        assert_eq!(target.get_str(reinterned), Some("hello"));
+        assert_eq!(target.get_interned("hello"), Some(reinterned));
+    }
+}
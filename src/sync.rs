@@ -0,0 +1,275 @@
+use std::{
+    cmp::max,
+    hash::BuildHasher,
+    num::NonZeroUsize,
+    sync::{Mutex, RwLock},
+};
+
+use hashbrown::{HashTable, hash_table::Entry};
+use rustc_hash::FxBuildHasher;
+
+use crate::{Istr, IstrRepr};
+
+/// The number of lock shards. Must be a power of two.
+const SHARDS: usize = 16;
+
+/// The default size of a concurrent arena chunk, in bytes.
+const DEFAULT_CHUNK: usize = 4096;
+
+#[derive(Clone, Copy)]
+struct Metadata<I: IstrRepr> {
+    interned: Istr<I>,
+    hash: u64,
+}
+
+/// A thread-safe string interner.
+///
+/// Unlike [`Interner`](crate::Interner), which uses a [`RefCell`](std::cell::RefCell)
+/// and is therefore `!Sync`, this can be called from multiple threads at once —
+/// making it suitable for a process-wide symbol pool, the way rustc and TAMER
+/// use interners.
+///
+/// Concurrency comes from two pieces:
+///
+/// - The dedup table is split into [`SHARDS`] shards, each behind its own
+///   [`RwLock`] and selected by the key's hash, so threads interning different
+///   strings rarely contend.
+/// - The string bytes live in an append-only arena of heap-stable chunks that
+///   are never moved or freed until the interner is dropped. The arena's mutex
+///   is held briefly for each push and each lookup, and is released before
+///   [`get_str`](SyncInterner::get_str) returns, so the `&str` it hands back is
+///   valid for the interner's lifetime without holding any lock for the
+///   caller.
+pub struct SyncInterner<I: IstrRepr = NonZeroUsize> {
+    shards: Box<[RwLock<HashTable<Metadata<I>>>]>,
+    arena: SyncArena,
+    random_state: FxBuildHasher,
+}
+
+impl<I: IstrRepr> Default for SyncInterner<I> {
+    fn default() -> Self {
+        let shards = (0..SHARDS)
+            .map(|_| RwLock::new(HashTable::default()))
+            .collect();
+        Self {
+            shards,
+            arena: SyncArena::default(),
+            random_state: FxBuildHasher,
+        }
+    }
+}
+
+impl SyncInterner {
+    /// Create a new thread-safe interner.
+    ///
+    /// Uses [`NonZeroUsize`](std::num::NonZeroUsize) as the [`Istr`] backing type.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<I: IstrRepr> SyncInterner<I> {
+    /// Create a new thread-safe interner with the inferred [`Istr`] backing type.
+    #[inline]
+    pub fn with_istr_repr() -> Self {
+        Self::default()
+    }
+
+    /// Intern a string, returning an interned string that it is cheap to copy
+    /// and perform equality checks on. Safe to call from multiple threads.
+    ///
+    /// # Panics
+    /// Panics if there are no more available IDs.
+    #[inline]
+    pub fn intern(&self, key: &str) -> Istr<I> {
+        self.try_intern(key).expect("too many interned strings")
+    }
+
+    /// Like [`SyncInterner::intern`], but non-panicking in the case that there
+    /// are no more available IDs.
+    pub fn try_intern(&self, key: &str) -> Option<Istr<I>> {
+        let hash = self.random_state.hash_one(key);
+        let shard = &self.shards[(hash as usize) & (SHARDS - 1)];
+
+        // Fast path: a shared read lock is enough if the string is already
+        // interned, which is the common case.
+        {
+            let table = shard.read().unwrap();
+            if let Some(metadata) = table.find(hash, |metadata| {
+                self.arena.get(metadata.interned.repr.to_index()) == Some(key)
+            }) {
+                return Some(metadata.interned);
+            }
+        }
+
+        // Slow path: take the write lock and use the entry API, which also
+        // copes with another thread having inserted the key in the meantime.
+        let mut table = shard.write().unwrap();
+        let entry = table.entry(
+            hash,
+            |metadata| self.arena.get(metadata.interned.repr.to_index()) == Some(key),
+            |metadata| metadata.hash,
+        );
+
+        let interned = match entry {
+            Entry::Occupied(entry) => entry.get().interned,
+            Entry::Vacant(entry) => {
+                let index = self.arena.push_str(key);
+                let interned = Istr {
+                    repr: I::from_index(index)?,
+                };
+                entry.insert(Metadata { interned, hash });
+                interned
+            }
+        };
+
+        Some(interned)
+    }
+
+    /// Get an interned string if this string is interned, otherwise return `None`.
+    pub fn get_interned(&self, key: &str) -> Option<Istr<I>> {
+        let hash = self.random_state.hash_one(key);
+        let shard = &self.shards[(hash as usize) & (SHARDS - 1)];
+
+        let table = shard.read().unwrap();
+        table
+            .find(hash, |metadata| {
+                self.arena.get(metadata.interned.repr.to_index()) == Some(key)
+            })
+            .map(|metadata| metadata.interned)
+    }
+
+    /// Look up an interned string to get the associated string.
+    ///
+    /// The returned reference is valid for the lifetime of the interner and
+    /// does not keep any lock held.
+    #[inline]
+    pub fn get_str(&self, interned: Istr<I>) -> Option<&str> {
+        self.arena.get(interned.repr.to_index())
+    }
+}
+
+struct SyncArenaInner {
+    /// Heap-stable chunks, never moved or freed until the arena is dropped.
+    chunks: Vec<Box<[u8]>>,
+    /// The number of bytes used in the last chunk.
+    current_len: usize,
+    /// A `(pointer, len)` pair per interned string, in insertion order.
+    spans: Vec<(*const u8, usize)>,
+}
+
+/// An append-only arena whose chunks never move, shared across threads.
+struct SyncArena {
+    inner: Mutex<SyncArenaInner>,
+}
+
+// SAFETY: all mutable state lives behind the `Mutex`, and the raw pointers in
+// `spans` only ever address bytes inside boxed chunks that are never freed or
+// moved until the arena drops, so reading them from any thread is sound.
+unsafe impl Send for SyncArena {}
+unsafe impl Sync for SyncArena {}
+
+impl Default for SyncArena {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: Mutex::new(SyncArenaInner {
+                chunks: Vec::new(),
+                current_len: 0,
+                spans: Vec::new(),
+            }),
+        }
+    }
+}
+
+impl SyncArena {
+    fn push_str(&self, s: &str) -> usize {
+        let mut inner = self.inner.lock().unwrap();
+
+        let needs_chunk = match inner.chunks.last() {
+            Some(chunk) => inner.current_len + s.len() > chunk.len(),
+            None => true,
+        };
+        if needs_chunk {
+            let cap = max(DEFAULT_CHUNK, s.len());
+            inner.chunks.push(vec![0u8; cap].into_boxed_slice());
+            inner.current_len = 0;
+        }
+
+        let start = inner.current_len;
+        let chunk = inner.chunks.last_mut().unwrap();
+        chunk[start..start + s.len()].copy_from_slice(s.as_bytes());
+        let ptr = chunk[start..].as_ptr();
+
+        inner.current_len += s.len();
+        let index = inner.spans.len();
+        inner.spans.push((ptr, s.len()));
+        index
+    }
+
+    fn get(&self, index: usize) -> Option<&str> {
+        let (ptr, len) = {
+            let inner = self.inner.lock().unwrap();
+            *inner.spans.get(index)?
+        };
+
+        // SAFETY: `ptr`/`len` describe bytes copied from a `&str` into a boxed
+        // chunk that outlives `self`, so they are valid UTF-8 for `'self` even
+        // though the lock has been released.
+        unsafe {
+            let bytes = std::slice::from_raw_parts(ptr, len);
+            Some(std::str::from_utf8_unchecked(bytes))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::Arc, thread};
+
+    use super::SyncInterner;
+
+    #[test]
+    fn dedups_on_a_single_thread() {
+        let interner = SyncInterner::new();
+
+        let a = interner.intern("hello");
+        let b = interner.intern("hello");
+        let world = interner.intern("world");
+
+        assert_eq!(a, b);
+        assert_ne!(a, world);
+        assert_eq!(interner.get_str(a), Some("hello"));
+        assert_eq!(interner.get_interned("hello"), Some(a));
+    }
+
+    #[test]
+    fn dedups_across_threads() {
+        // Enough distinct strings to spill past a single arena chunk.
+        const COUNT: usize = 1000;
+        let string = |n: usize| format!("symbol-number-{n}");
+
+        let interner = Arc::new(SyncInterner::new());
+
+        let results: Vec<Vec<_>> = (0..8)
+            .map(|_| {
+                let interner = Arc::clone(&interner);
+                thread::spawn(move || (0..COUNT).map(|n| interner.intern(&string(n))).collect())
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect();
+
+        // Every thread must agree on the ID assigned to each string.
+        for other in &results[1..] {
+            assert_eq!(&results[0], other);
+        }
+
+        // And every string still reads back correctly from the boxed-chunk arena.
+        for (n, &id) in results[0].iter().enumerate() {
+            assert_eq!(interner.get_str(id).map(str::to_owned), Some(string(n)));
+        }
+    }
+}
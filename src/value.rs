@@ -0,0 +1,401 @@
+use std::{
+    cell::{RefCell, UnsafeCell},
+    cmp::max,
+    fmt,
+    hash::{BuildHasher, Hash},
+    marker::PhantomData,
+    num::NonZeroUsize,
+};
+
+use hashbrown::{HashTable, hash_table::Entry};
+use rustc_hash::FxBuildHasher;
+
+use crate::IstrRepr;
+
+/// An ID for an interned value of type `T`, analogous to
+/// [`Istr`](crate::Istr) but carrying the interned type in its signature.
+///
+/// Like [`Istr`], it is internally just an integer ID, so it is cheap to copy
+/// and to compare for equality. It is only meaningful when looked up in the
+/// interner it came from.
+#[repr(transparent)]
+pub struct Id<T: ?Sized, I: IstrRepr = NonZeroUsize> {
+    repr: I,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: ?Sized, I: IstrRepr> Id<T, I> {
+    #[inline]
+    fn from_index(index: usize) -> Option<Self> {
+        Some(Self {
+            repr: I::from_index(index)?,
+            _marker: PhantomData,
+        })
+    }
+
+    #[inline]
+    fn to_index(self) -> usize {
+        self.repr.to_index()
+    }
+}
+
+// Derived impls would spuriously require `T: Clone`/`Eq`/…, so they are written
+// out by hand over `I` alone.
+impl<T: ?Sized, I: IstrRepr> Clone for Id<T, I> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized, I: IstrRepr> Copy for Id<T, I> {}
+
+impl<T: ?Sized, I: IstrRepr + PartialEq> PartialEq for Id<T, I> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.repr == other.repr
+    }
+}
+
+impl<T: ?Sized, I: IstrRepr + Eq> Eq for Id<T, I> {}
+
+impl<T: ?Sized, I: IstrRepr + Hash> Hash for Id<T, I> {
+    #[inline]
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.repr.hash(state);
+    }
+}
+
+impl<T: ?Sized, I: IstrRepr + fmt::Debug> fmt::Debug for Id<T, I> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Id").field(&self.repr).finish()
+    }
+}
+
+struct Metadata<T: ?Sized, I: IstrRepr> {
+    id: Id<T, I>,
+    hash: u64,
+}
+
+impl<T: ?Sized, I: IstrRepr> Clone for Metadata<T, I> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ?Sized, I: IstrRepr> Copy for Metadata<T, I> {}
+
+struct Lookup<T: ?Sized, I: IstrRepr> {
+    random_state: FxBuildHasher,
+    table: HashTable<Metadata<T, I>>,
+}
+
+impl<T: ?Sized, I: IstrRepr> Default for Lookup<T, I> {
+    fn default() -> Self {
+        Self {
+            random_state: FxBuildHasher,
+            table: HashTable::default(),
+        }
+    }
+}
+
+/// Storage for interned values of an arbitrary `Hash + Eq` type.
+///
+/// This is the generalisation of [`Interner`](crate::Interner) to any owned
+/// value: each distinct value is stored once in a boxed slot (so the `&T`
+/// handed out by [`get`](ValueInterner::get) stays valid for the interner's
+/// lifetime) and deduplicated through the same [`HashTable`] scheme that backs
+/// the string interner.
+pub struct ValueInterner<T, I: IstrRepr = NonZeroUsize> {
+    lookup: RefCell<Lookup<T, I>>,
+    values: UnsafeCell<Vec<Box<T>>>,
+}
+
+impl<T, I: IstrRepr> Default for ValueInterner<T, I> {
+    fn default() -> Self {
+        Self {
+            lookup: RefCell::new(Lookup::default()),
+            values: UnsafeCell::new(Vec::new()),
+        }
+    }
+}
+
+impl<T: Hash + Eq> ValueInterner<T> {
+    /// Create a new value interner.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<T: Hash + Eq, I: IstrRepr> ValueInterner<T, I> {
+    /// Intern a value, returning an [`Id`] that is cheap to copy and compare.
+    /// Equal values are only stored once, no matter how many times they are
+    /// interned.
+    ///
+    /// # Panics
+    /// Panics if there are no more available IDs.
+    #[inline]
+    pub fn intern(&self, value: T) -> Id<T, I> {
+        self.try_intern(value).expect("too many interned values")
+    }
+
+    /// Like [`ValueInterner::intern`], but non-panicking in the case that there
+    /// are no more available IDs.
+    pub fn try_intern(&self, value: T) -> Option<Id<T, I>> {
+        let mut lookup = self.lookup.borrow_mut();
+
+        let hash = lookup.random_state.hash_one(&value);
+
+        let entry = lookup.table.entry(
+            hash,
+            |metadata| self.value_at(metadata.id.to_index()) == Some(&value),
+            |metadata| metadata.hash,
+        );
+
+        let id = match entry {
+            Entry::Occupied(entry) => entry.get().id,
+            Entry::Vacant(entry) => {
+                let index = self.push_value(value);
+                let id = Id::from_index(index)?;
+                entry.insert(Metadata { id, hash });
+                id
+            }
+        };
+
+        Some(id)
+    }
+
+    /// Get the [`Id`] for a value if it is interned, otherwise return `None`.
+    pub fn get_id(&self, value: &T) -> Option<Id<T, I>> {
+        let lookup = self.lookup.borrow();
+
+        let hash = lookup.random_state.hash_one(value);
+
+        lookup
+            .table
+            .find(hash, |metadata| {
+                self.value_at(metadata.id.to_index()) == Some(value)
+            })
+            .map(|metadata| metadata.id)
+    }
+
+    /// Look up an [`Id`] to get the associated value.
+    #[inline]
+    pub fn get(&self, id: Id<T, I>) -> Option<&T> {
+        self.value_at(id.to_index())
+    }
+
+    fn push_value(&self, value: T) -> usize {
+        // SAFETY: the reference returned by `value_at` points into a `Box`, not
+        // into the `Vec` itself, so growing the `Vec` here never invalidates it.
+        let values = unsafe { &mut *self.values.get() };
+        let index = values.len();
+        values.push(Box::new(value));
+        index
+    }
+
+    fn value_at(&self, index: usize) -> Option<&T> {
+        // SAFETY: this only reads the `Vec`; the `&T` borrows the heap-stable
+        // box and so is valid for the lifetime of `self`.
+        let values = unsafe { &*self.values.get() };
+        values.get(index).map(|boxed| boxed.as_ref())
+    }
+}
+
+struct ByteArenaInner {
+    full: Vec<Vec<u8>>,
+    current: Vec<u8>,
+    spans: Vec<(*const u8, usize)>,
+}
+
+/// A chunked arena storing interned byte slices, mirroring the string arena.
+struct ByteArena {
+    inner: UnsafeCell<ByteArenaInner>,
+}
+
+impl Default for ByteArena {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            inner: UnsafeCell::new(ByteArenaInner {
+                full: Vec::new(),
+                current: Vec::new(),
+                spans: Vec::new(),
+            }),
+        }
+    }
+}
+
+impl ByteArena {
+    fn push(&self, bytes: &[u8]) -> usize {
+        // SAFETY: see the string arena; `get` never aliases these fields.
+        let inner = unsafe { &mut *self.inner.get() };
+
+        if inner.current.len() + bytes.len() > inner.current.capacity() {
+            let cap = max(4096, bytes.len());
+            let old = std::mem::replace(&mut inner.current, Vec::with_capacity(cap));
+            if !old.is_empty() {
+                // Push the buffer whole: `into_boxed_slice` would shrink it to
+                // `len == capacity`, reallocating (and freeing the old buffer)
+                // whenever the chunk was not exactly full, leaving every `spans`
+                // pointer into it dangling.
+                inner.full.push(old);
+            }
+        }
+
+        let start = inner.current.len();
+        inner.current.extend_from_slice(bytes);
+
+        // SAFETY: `current` had spare capacity, so `extend_from_slice` did not
+        // reallocate and this pointer stays valid until the chunk is dropped.
+        let ptr = unsafe { inner.current.as_ptr().add(start) };
+
+        let index = inner.spans.len();
+        inner.spans.push((ptr, bytes.len()));
+        index
+    }
+
+    fn get(&self, index: usize) -> Option<&[u8]> {
+        // SAFETY: see `push`; this only reads the arena's contents.
+        let inner = unsafe { &*self.inner.get() };
+        let &(ptr, len) = inner.spans.get(index)?;
+
+        // SAFETY: `ptr`/`len` describe bytes written by `push` and live as long
+        // as `self`.
+        unsafe { Some(std::slice::from_raw_parts(ptr, len)) }
+    }
+}
+
+/// Storage for interned byte slices, for binary tokens or identifiers that may
+/// contain non-UTF-8 data.
+///
+/// This is the `[u8]` specialisation of [`ValueInterner`]: bytes are copied
+/// into a chunked arena (so [`get`](ByteInterner::get) returns a `&[u8]` valid
+/// for the interner's lifetime) and deduplicated by slice equality, exactly as
+/// the string path compares stored text.
+pub struct ByteInterner<I: IstrRepr = NonZeroUsize> {
+    lookup: RefCell<Lookup<[u8], I>>,
+    arena: ByteArena,
+}
+
+impl<I: IstrRepr> Default for ByteInterner<I> {
+    fn default() -> Self {
+        Self {
+            lookup: RefCell::new(Lookup::default()),
+            arena: ByteArena::default(),
+        }
+    }
+}
+
+impl ByteInterner {
+    /// Create a new byte-slice interner.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<I: IstrRepr> ByteInterner<I> {
+    /// Intern a byte slice, returning an [`Id`] that is cheap to copy and
+    /// compare. Equal slices are only stored once.
+    ///
+    /// # Panics
+    /// Panics if there are no more available IDs.
+    #[inline]
+    pub fn intern(&self, key: &[u8]) -> Id<[u8], I> {
+        self.try_intern(key).expect("too many interned values")
+    }
+
+    /// Like [`ByteInterner::intern`], but non-panicking in the case that there
+    /// are no more available IDs.
+    pub fn try_intern(&self, key: &[u8]) -> Option<Id<[u8], I>> {
+        let mut lookup = self.lookup.borrow_mut();
+
+        let hash = lookup.random_state.hash_one(key);
+
+        let entry = lookup.table.entry(
+            hash,
+            |metadata| self.arena.get(metadata.id.to_index()) == Some(key),
+            |metadata| metadata.hash,
+        );
+
+        let id = match entry {
+            Entry::Occupied(entry) => entry.get().id,
+            Entry::Vacant(entry) => {
+                let index = self.arena.push(key);
+                let id = Id::from_index(index)?;
+                entry.insert(Metadata { id, hash });
+                id
+            }
+        };
+
+        Some(id)
+    }
+
+    /// Get the [`Id`] for a byte slice if it is interned, otherwise `None`.
+    pub fn get_id(&self, key: &[u8]) -> Option<Id<[u8], I>> {
+        let lookup = self.lookup.borrow();
+
+        let hash = lookup.random_state.hash_one(key);
+
+        lookup
+            .table
+            .find(hash, |metadata| {
+                self.arena.get(metadata.id.to_index()) == Some(key)
+            })
+            .map(|metadata| metadata.id)
+    }
+
+    /// Look up an [`Id`] to get the associated byte slice.
+    #[inline]
+    pub fn get(&self, id: Id<[u8], I>) -> Option<&[u8]> {
+        self.arena.get(id.to_index())
+    }
+}
+
+#[test]
+fn test_value_interner() {
+    let interner = ValueInterner::new();
+
+    let a = interner.intern(vec![1u32, 2, 3]);
+    let b = interner.intern(vec![1u32, 2, 3]);
+    let other = interner.intern(vec![4u32]);
+
+    assert_eq!(a, b);
+    assert_ne!(a, other);
+    assert_eq!(interner.get(a), Some(&vec![1u32, 2, 3]));
+    assert_eq!(interner.get_id(&vec![4u32]), Some(other));
+    assert_eq!(interner.get_id(&vec![9u32]), None);
+}
+
+#[test]
+fn test_byte_interner() {
+    let interner = ByteInterner::new();
+
+    // Non-UTF-8 bytes are fine.
+    let a = interner.intern(&[0xff, 0x00, 0xfe]);
+    let b = interner.intern(&[0xff, 0x00, 0xfe]);
+    let other = interner.intern(b"abc");
+
+    assert_eq!(a, b);
+    assert_ne!(a, other);
+    assert_eq!(interner.get(a), Some(&[0xff, 0x00, 0xfe][..]));
+    assert_eq!(interner.get_id(b"abc"), Some(other));
+}
+
+#[test]
+fn byte_interner_spans_multiple_chunks() {
+    let interner = ByteInterner::new();
+
+    // Intern enough distinct slices to spill well past a single 4096-byte
+    // chunk, then check every one still reads back correctly — this exercises
+    // the chunk rotation that the dangling-pointer fix depends on.
+    let slices: Vec<Vec<u8>> = (0..4096u32).map(|n| n.to_le_bytes().to_vec()).collect();
+    let ids: Vec<_> = slices.iter().map(|s| interner.intern(s)).collect();
+
+    for (slice, &id) in slices.iter().zip(&ids) {
+        assert_eq!(interner.get(id), Some(slice.as_slice()));
+    }
+}